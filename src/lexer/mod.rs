@@ -29,6 +29,7 @@ pub enum Token {
 pub struct Lexer {
     input: Vec<char>,
     pos: usize,
+    after_operand: bool, // Previous token can end an operand, so '-' is binary
 }
 
 impl Lexer {
@@ -36,10 +37,26 @@ impl Lexer {
         Lexer {
             input: input.chars().collect(),
             pos: 0,
+            after_operand: false,
         }
     }
 
     pub fn next_token(&mut self) -> Token {
+        let token = self.lex_token();
+        self.after_operand = Self::ends_operand(&token);
+        token
+    }
+
+    fn ends_operand(token: &Token) -> bool {
+        match token {
+            Token::Identifier(id) => id.starts_with(|c: char| c.is_alphanumeric() || c == '_'),
+            Token::Integer(_) | Token::Float(_) | Token::Complex(..) | Token::DnaSequence(_)
+            | Token::Superpos(_) | Token::RParen | Token::RBracket => true,
+            _ => false,
+        }
+    }
+
+    fn lex_token(&mut self) -> Token {
         self.skip_whitespace();
         if self.pos >= self.input.len() {
             return Token::Eof;
@@ -51,12 +68,19 @@ impl Lexer {
         match ch {
             '(' => Token::LParen,
             ')' => Token::RParen,
-            '[' => Token::LBracket,
+            '[' => self.parse_dna_sequence(),
             ']' => Token::RBracket,
             '{' => Token::LBrace,
             '}' => Token::RBrace,
             '+' => Token::Plus,
-            '-' => if self.peek() == '>' { self.pos += 1; Token::QuantumMeasure } else { Token::Minus },
+            '-' => if self.peek() == '>' {
+                self.pos += 1;
+                Token::QuantumMeasure
+            } else if !self.after_operand && self.peek().is_ascii_digit() {
+                self.parse_negative_complex()
+            } else {
+                Token::Minus
+            },
             '*' => Token::Mul,
             '/' => Token::Div,
             '=' => Token::Assign,
@@ -114,12 +138,25 @@ impl Lexer {
             "entangle" | "wavecore" | "swarmbot" => Token::Keyword(id),
             "sin~" | "cos~" | "tan~" => Token::WaveOp(id), // Trig-formula wave cores
             _ if id.starts_with("bell_phi") || id.starts_with("bell_psi") => Token::BellState(id),
-            _ if id.starts_with("[") && id.ends_with("]") && id.chars().skip(1).take(id.len()-2).all(|c| "ATGC".contains(c)) => Token::DnaSequence(id),
             _ if id.starts_with("swarm:") => Token::SwarmBot(id), // Strategickhaos integration stub
             _ => Token::Identifier(id),
         }
     }
 
+    fn parse_dna_sequence(&mut self) -> Token {
+        // DNA literal: [ATGC...]; anything else is a plain bracket
+        let mut end = self.pos;
+        while end < self.input.len() && "ATGC".contains(self.input[end]) {
+            end += 1;
+        }
+        if end == self.pos || end >= self.input.len() || self.input[end] != ']' {
+            return Token::LBracket;
+        }
+        let seq: String = self.input[self.pos..end].iter().collect();
+        self.pos = end + 1;
+        Token::DnaSequence(format!("[{}]", seq))
+    }
+
     fn parse_superpos(&mut self, first: char) -> Token {
         let mut sup = first.to_string();
         while self.pos < self.input.len() && self.input[self.pos] != '>' {
@@ -140,20 +177,23 @@ impl Lexer {
             self.pos += 1;
         }
         if self.peek() == '+' || self.peek() == '-' {
-            num.push(self.input[self.pos]);
+            // Parse complex: real ± imag i, keeping the sign of the imaginary part
+            let start = self.pos;
+            let sign = if self.input[self.pos] == '-' { -1.0 } else { 1.0 };
             self.pos += 1;
-            while self.pos < self.input.len() && self.input[self.pos].is_digit(10) {
-                num.push(self.input[self.pos]);
+            let mut imag = String::new();
+            while self.pos < self.input.len() && (self.input[self.pos].is_ascii_digit() || self.input[self.pos] == '.') {
+                imag.push(self.input[self.pos]);
                 self.pos += 1;
             }
-            if self.peek() == 'i' {
+            if !imag.is_empty() && self.peek() == 'i' {
                 self.pos += 1;
-                // Parse complex: real + imag i
-                let parts: Vec<&str> = num.split(['+', '-']).collect();
-                let real = parts[0].parse::<f64>().unwrap_or(0.0);
-                let imag = parts[1].parse::<f64>().unwrap_or(0.0);
-                return Token::Complex(real, imag);
+                let real = num.parse::<f64>().unwrap_or(0.0);
+                let imag = imag.parse::<f64>().unwrap_or(0.0);
+                return Token::Complex(real, sign * imag);
             }
+            // Not a complex literal: leave the operator for the next token
+            self.pos = start;
         }
         if num.contains('.') {
             Token::Float(num.parse().unwrap_or(0.0))
@@ -162,6 +202,20 @@ impl Lexer {
         }
     }
 
+    fn parse_negative_complex(&mut self) -> Token {
+        // Leading minus on the real part, e.g. -2-3i; plain negatives stay Minus + number
+        let start = self.pos;
+        let first = self.input[self.pos];
+        self.pos += 1;
+        match self.parse_number(first) {
+            Token::Complex(real, imag) => Token::Complex(-real, imag),
+            _ => {
+                self.pos = start;
+                Token::Minus
+            }
+        }
+    }
+
     fn parse_reason_stub(&mut self) -> Token {
         // AI agent scaffolding: Parse #reason{query} for GPT contribution hooks
         if self.peek() != '{' { return Token::Keyword("#reason".to_string()); }
@@ -206,4 +260,58 @@ mod tests {
         assert_eq!(lexer.next_token(), Token::GateOp("X".to_string()));
         assert_eq!(lexer.next_token(), Token::Eof);
     }
+
+    #[test]
+    fn test_complex_signs() {
+        let mut lexer = Lexer::new("-2-3i; 3-4i 0+1i 5 - 3");
+        assert_eq!(lexer.next_token(), Token::Complex(-2.0, -3.0));
+        assert_eq!(lexer.next_token(), Token::Semicolon);
+        assert_eq!(lexer.next_token(), Token::Complex(3.0, -4.0));
+        assert_eq!(lexer.next_token(), Token::Complex(0.0, 1.0));
+        assert_eq!(lexer.next_token(), Token::Integer(5));
+        assert_eq!(lexer.next_token(), Token::Minus);
+        assert_eq!(lexer.next_token(), Token::Integer(3));
+        assert_eq!(lexer.next_token(), Token::Eof);
+    }
+
+    #[test]
+    fn test_binary_minus_before_number() {
+        let mut lexer = Lexer::new("5-3");
+        assert_eq!(lexer.next_token(), Token::Integer(5));
+        assert_eq!(lexer.next_token(), Token::Minus);
+        assert_eq!(lexer.next_token(), Token::Integer(3));
+        assert_eq!(lexer.next_token(), Token::Eof);
+
+        let mut lexer = Lexer::new("5-2+3i");
+        assert_eq!(lexer.next_token(), Token::Integer(5));
+        assert_eq!(lexer.next_token(), Token::Minus);
+        assert_eq!(lexer.next_token(), Token::Complex(2.0, 3.0));
+        assert_eq!(lexer.next_token(), Token::Eof);
+
+        let mut lexer = Lexer::new("(x)-2-3i");
+        assert_eq!(lexer.next_token(), Token::LParen);
+        assert_eq!(lexer.next_token(), Token::Identifier("x".to_string()));
+        assert_eq!(lexer.next_token(), Token::RParen);
+        assert_eq!(lexer.next_token(), Token::Minus);
+        assert_eq!(lexer.next_token(), Token::Complex(2.0, -3.0));
+        assert_eq!(lexer.next_token(), Token::Eof);
+
+        let mut lexer = Lexer::new("x = -2+3i");
+        assert_eq!(lexer.next_token(), Token::Identifier("x".to_string()));
+        assert_eq!(lexer.next_token(), Token::Assign);
+        assert_eq!(lexer.next_token(), Token::Complex(-2.0, 3.0));
+        assert_eq!(lexer.next_token(), Token::Eof);
+    }
+
+    #[test]
+    fn test_dna_sequence() {
+        let mut lexer = Lexer::new("[ATGC] [1,2]");
+        assert_eq!(lexer.next_token(), Token::DnaSequence("[ATGC]".to_string()));
+        assert_eq!(lexer.next_token(), Token::LBracket);
+        assert_eq!(lexer.next_token(), Token::Integer(1));
+        assert_eq!(lexer.next_token(), Token::Identifier(",".to_string()));
+        assert_eq!(lexer.next_token(), Token::Integer(2));
+        assert_eq!(lexer.next_token(), Token::RBracket);
+        assert_eq!(lexer.next_token(), Token::Eof);
+    }
 }